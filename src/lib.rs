@@ -35,8 +35,17 @@ macro_rules! assert_matches {
     ( $e:expr , $pat:pat if $cond:expr ) => {
         match $e {
             $pat if $cond => (),
-            ref e => panic!("assertion failed: `{:?}` does not match `{} if {}`",
-                e, stringify!($pat), stringify!($cond))
+            ref e => {
+                #[allow(unused_variables)]
+                let pattern_matched = matches!(e, $pat);
+                if pattern_matched {
+                    panic!("assertion failed: value `{:?}` matched pattern `{}` but guard `{}` evaluated to false",
+                        e, stringify!($pat), stringify!($cond))
+                } else {
+                    panic!("assertion failed: `{:?}` does not match `{} if {}`",
+                        e, stringify!($pat), stringify!($cond))
+                }
+            }
         }
     };
     ( $e:expr , $pat:pat => $arm:expr ) => {
@@ -49,8 +58,17 @@ macro_rules! assert_matches {
     ( $e:expr , $pat:pat if $cond:expr => $arm:expr ) => {
         match $e {
             $pat if $cond => $arm,
-            ref e => panic!("assertion failed: `{:?}` does not match `{} if {}`",
-                e, stringify!($pat), stringify!($cond))
+            ref e => {
+                #[allow(unused_variables)]
+                let pattern_matched = matches!(e, $pat);
+                if pattern_matched {
+                    panic!("assertion failed: value `{:?}` matched pattern `{}` but guard `{}` evaluated to false",
+                        e, stringify!($pat), stringify!($cond))
+                } else {
+                    panic!("assertion failed: `{:?}` does not match `{} if {}`",
+                        e, stringify!($pat), stringify!($cond))
+                }
+            }
         }
     };
     ( $e:expr , $pat:pat , $($arg:tt)* ) => {
@@ -63,8 +81,17 @@ macro_rules! assert_matches {
     ( $e:expr , $pat:pat if $cond:expr , $($arg:tt)* ) => {
         match $e {
             $pat if $cond => (),
-            ref e => panic!("assertion failed: `{:?}` does not match `{} if {}`: {}",
-                e, stringify!($pat), stringify!($cond), format_args!($($arg)*))
+            ref e => {
+                #[allow(unused_variables)]
+                let pattern_matched = matches!(e, $pat);
+                if pattern_matched {
+                    panic!("assertion failed: value `{:?}` matched pattern `{}` but guard `{}` evaluated to false: {}",
+                        e, stringify!($pat), stringify!($cond), format_args!($($arg)*))
+                } else {
+                    panic!("assertion failed: `{:?}` does not match `{} if {}`: {}",
+                        e, stringify!($pat), stringify!($cond), format_args!($($arg)*))
+                }
+            }
         }
     };
     ( $e:expr , $pat:pat => $arm:expr , $($arg:tt)* ) => {
@@ -77,8 +104,272 @@ macro_rules! assert_matches {
     ( $e:expr , $pat:pat if $cond:expr => $arm:expr , $($arg:tt)* ) => {
         match $e {
             $pat if $cond => $arm,
-            ref e => panic!("assertion failed: `{:?}` does not match `{} if {}`: {}",
-                e, stringify!($pat), stringify!($cond), format_args!($($arg)*))
+            ref e => {
+                #[allow(unused_variables)]
+                let pattern_matched = matches!(e, $pat);
+                if pattern_matched {
+                    panic!("assertion failed: value `{:?}` matched pattern `{}` but guard `{}` evaluated to false: {}",
+                        e, stringify!($pat), stringify!($cond), format_args!($($arg)*))
+                } else {
+                    panic!("assertion failed: `{:?}` does not match `{} if {}`: {}",
+                        e, stringify!($pat), stringify!($cond), format_args!($($arg)*))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an expression matches a given pattern, with an optional guard
+/// expression, but only when `cfg!(debug_assertions)` is true.
+///
+/// Like `debug_assert!`, this macro is disabled in release builds by default.
+/// It is useful for match checks that are too expensive to keep around when
+/// `debug_assertions` are turned off.
+///
+/// ```ignore
+/// #[macro_use] extern crate assert_matches;
+///
+/// #[derive(Debug)]
+/// enum Foo {
+///     A(i32),
+///     B(i32),
+/// }
+///
+/// let a = Foo::A(1);
+///
+/// debug_assert_matches!(a, Foo::A(_));
+///
+/// debug_assert_matches!(a, Foo::A(i) if i > 0);
+///
+/// debug_assert_matches!(a, Foo::A(i) if i > 0 => assert!(i != 0));
+/// ```
+#[macro_export]
+macro_rules! debug_assert_matches {
+    ( $e:expr , $pat:pat ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat)
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat if $cond)
+        }
+    };
+    ( $e:expr , $pat:pat => $arm:expr ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat => $arm)
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr => $arm:expr ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat if $cond => $arm)
+        }
+    };
+    ( $e:expr , $pat:pat , $($arg:tt)* ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat, $($arg)*)
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr , $($arg:tt)* ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat if $cond, $($arg)*)
+        }
+    };
+    ( $e:expr , $pat:pat => $arm:expr , $($arg:tt)* ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat => $arm, $($arg)*)
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr => $arm:expr , $($arg:tt)* ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_matches!($e, $pat if $cond => $arm, $($arg)*)
+        }
+    };
+}
+
+/// Asserts that an expression does not match a given pattern, with an
+/// optional guard expression.
+///
+/// This is the inverse of `assert_matches!`: it panics if the value *does*
+/// match the pattern, which is useful for confirming a value avoided some
+/// forbidden shape without having to spell out every other possibility.
+///
+/// ```ignore
+/// #[macro_use] extern crate assert_matches;
+///
+/// #[derive(Debug)]
+/// enum Foo {
+///     A(i32),
+///     B(i32),
+/// }
+///
+/// let a = Foo::A(1);
+///
+/// assert_not_matches!(a, Foo::B(_));
+///
+/// assert_not_matches!(a, Foo::B(i) if *i > 0);
+/// ```
+#[macro_export]
+macro_rules! assert_not_matches {
+    ( $e:expr , $pat:pat ) => {
+        match &$e {
+            e @ $pat => panic!("assertion failed: `{:?}` matches `{}` but was expected not to",
+                e, stringify!($pat)),
+            _ => ()
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr ) => {
+        match &$e {
+            e @ $pat if $cond => panic!("assertion failed: `{:?}` matches `{} if {}` but was expected not to",
+                e, stringify!($pat), stringify!($cond)),
+            _ => ()
+        }
+    };
+    ( $e:expr , $pat:pat , $($arg:tt)* ) => {
+        match &$e {
+            e @ $pat => panic!("assertion failed: `{:?}` matches `{}` but was expected not to: {}",
+                e, stringify!($pat), format_args!($($arg)*)),
+            _ => ()
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr , $($arg:tt)* ) => {
+        match &$e {
+            e @ $pat if $cond => panic!("assertion failed: `{:?}` matches `{} if {}` but was expected not to: {}",
+                e, stringify!($pat), stringify!($cond), format_args!($($arg)*)),
+            _ => ()
+        }
+    };
+}
+
+/// Asserts that an expression does not match a given pattern, with an
+/// optional guard expression, but only when `cfg!(debug_assertions)` is
+/// true.
+///
+/// Like `debug_assert_matches!`, this macro is disabled in release builds.
+///
+/// ```ignore
+/// #[macro_use] extern crate assert_matches;
+///
+/// #[derive(Debug)]
+/// enum Foo {
+///     A(i32),
+///     B(i32),
+/// }
+///
+/// let a = Foo::A(1);
+///
+/// debug_assert_not_matches!(a, Foo::B(_));
+///
+/// debug_assert_not_matches!(a, Foo::B(i) if *i > 0);
+/// ```
+#[macro_export]
+macro_rules! debug_assert_not_matches {
+    ( $e:expr , $pat:pat ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_not_matches!($e, $pat)
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_not_matches!($e, $pat if $cond)
+        }
+    };
+    ( $e:expr , $pat:pat , $($arg:tt)* ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_not_matches!($e, $pat, $($arg)*)
+        }
+    };
+    ( $e:expr , $pat:pat if $cond:expr , $($arg:tt)* ) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_not_matches!($e, $pat if $cond, $($arg)*)
+        }
+    };
+}
+
+/// Asserts that an expression matches a given pattern, binding the
+/// pattern's variables into the enclosing scope on success.
+///
+/// This is a statement-position macro, used in place of `let` wherever the
+/// pattern on the left-hand side is refutable. It turns the common
+/// "unwrap a specific enum variant and keep its fields" idiom into one
+/// line, instead of nesting the rest of the function inside an
+/// `assert_matches!` arm.
+///
+/// Unlike the other macros in this crate, this one requires Rust 1.65
+/// (`let`-`else`): letting an arbitrary pattern's bindings escape into the
+/// surrounding block isn't otherwise expressible from a declarative macro,
+/// since `macro_rules!` has no way to enumerate a `pat` fragment's bound
+/// names to rebuild an irrefutable destination pattern for a plain `let`.
+///
+/// ```ignore
+/// #[macro_use] extern crate assert_matches;
+///
+/// #[derive(Debug)]
+/// enum Foo {
+///     A(i32),
+///     B(i32),
+/// }
+///
+/// let a = Foo::A(1);
+///
+/// assert_let!(Foo::A(n) = a);
+///
+/// assert_eq!(n, 1);
+/// ```
+#[macro_export]
+macro_rules! assert_let {
+    ( $pat:pat = $e:expr ) => {
+        let __assert_let_value = $e;
+        let $pat = __assert_let_value else {
+            panic!("assertion failed: `{:?}` does not match `{}`",
+                __assert_let_value, stringify!($pat))
+        };
+    };
+    ( $pat:pat = $e:expr , $($arg:tt)* ) => {
+        let __assert_let_value = $e;
+        let $pat = __assert_let_value else {
+            panic!("assertion failed: `{:?}` does not match `{}`: {}",
+                __assert_let_value, stringify!($pat), format_args!($($arg)*))
+        };
+    };
+}
+
+/// Asserts that an expression matches any one of a list of patterns, each
+/// with an optional guard expression.
+///
+/// This is more ergonomic than hand-writing `|`-patterns when the
+/// alternatives have incompatible bindings. On failure, the panic message
+/// lists every pattern that was tried.
+///
+/// ```ignore
+/// #[macro_use] extern crate assert_matches;
+///
+/// #[derive(Debug)]
+/// enum Foo {
+///     A(i32),
+///     B(i32),
+///     C(i32),
+/// }
+///
+/// let a = Foo::A(1);
+///
+/// assert_matches_any!(a, [Foo::A(_), Foo::B(i) if i > 0, Foo::C(_)]);
+/// ```
+#[macro_export]
+macro_rules! assert_matches_any {
+    ( $e:expr , [ $( $pat:pat $(if $cond:expr)? ),+ $(,)? ] ) => {
+        match $e {
+            $( $pat $(if $cond)? => (), )+
+            ref e => panic!("assertion failed: value `{:?}` matched none of: {}",
+                e, [ $( concat!("`", stringify!($pat $(if $cond)?), "`") ),+ ].join(", "))
+        }
+    };
+    ( $e:expr , [ $( $pat:pat $(if $cond:expr)? ),+ $(,)? ] , $($arg:tt)* ) => {
+        match $e {
+            $( $pat $(if $cond)? => (), )+
+            ref e => panic!("assertion failed: value `{:?}` matched none of: {}: {}",
+                e, [ $( concat!("`", stringify!($pat $(if $cond)?), "`") ),+ ].join(", "),
+                format_args!($($arg)*))
         }
     };
 }
@@ -134,6 +425,22 @@ mod test {
         assert_matches!(b, Foo::B(s) if s == "bar");
     }
 
+    #[test]
+    #[should_panic(expected = "does not match")]
+    fn test_assert_panic_pattern_mismatch() {
+        let b = Foo::B("foo");
+
+        assert_matches!(b, Foo::A(_) if true);
+    }
+
+    #[test]
+    #[should_panic(expected = "matched pattern `Foo::B(s)` but guard `s == \"bar\"` evaluated to false")]
+    fn test_assert_panic_guard_mismatch() {
+        let b = Foo::B("foo");
+
+        assert_matches!(b, Foo::B(s) if s == "bar");
+    }
+
     #[test]
     #[should_panic]
     fn test_assert_panic_3() {
@@ -192,4 +499,130 @@ mod test {
         assert_matches!(a, Foo::A(n) => { assert_eq!(n, 0); assert!(n < 1) }, "o noes {value:?}", value=a);
         assert_matches!(a, Foo::A(n) if n == 0 => assert_eq!(n, 0), "o noes {value:?}", value=a);
     }
+
+    #[test]
+    fn test_debug_assert_succeed() {
+        let a = Foo::A(123);
+
+        debug_assert_matches!(a, Foo::A(_));
+        debug_assert_matches!(a, Foo::A(123));
+        debug_assert_matches!(a, Foo::A(i) if i == 123);
+        debug_assert_matches!(a, Foo::A(i) if i == 123 => assert_eq!(i, 123));
+        debug_assert_matches!(a, Foo::A(_), "o noes");
+        debug_assert_matches!(a, Foo::A(i) if i == 123, "o noes");
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_debug_assert_panic() {
+        let a = Foo::A(123);
+
+        debug_assert_matches!(a, Foo::B(_));
+    }
+
+    #[test]
+    fn test_assert_not_matches_succeed() {
+        let a = Foo::A(123);
+
+        assert_not_matches!(a, Foo::B(_));
+        assert_not_matches!(a, Foo::A(n) if *n != 123);
+        assert_not_matches!(a, Foo::B(_), "o noes");
+        assert_not_matches!(a, Foo::A(n) if *n != 123, "o noes {:?}", a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_not_matches_panic_0() {
+        let a = Foo::A(123);
+
+        assert_not_matches!(a, Foo::A(_));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_not_matches_panic_1() {
+        let a = Foo::A(123);
+
+        assert_not_matches!(a, Foo::A(n) if *n == 123);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_not_matches_panic_2() {
+        let a = Foo::A(123);
+
+        assert_not_matches!(a, Foo::A(_), "o noes");
+    }
+
+    #[test]
+    fn test_assert_not_matches_no_move() {
+        let b = &mut Foo::A(0);
+        assert_not_matches!(*b, Foo::B(_));
+    }
+
+    #[test]
+    fn test_debug_assert_not_matches_succeed() {
+        let a = Foo::A(123);
+
+        debug_assert_not_matches!(a, Foo::B(_));
+        debug_assert_not_matches!(a, Foo::A(n) if *n != 123);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_debug_assert_not_matches_panic() {
+        let a = Foo::A(123);
+
+        debug_assert_not_matches!(a, Foo::A(_));
+    }
+
+    #[test]
+    fn test_assert_let_succeed() {
+        let a = Foo::A(123);
+        assert_let!(Foo::A(n) = a);
+        assert_eq!(n, 123);
+
+        let b = Foo::B("foo");
+        assert_let!(Foo::B(s) = b, "o noes");
+        assert_eq!(s, "foo");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_let_panic() {
+        let a = Foo::A(123);
+        assert_let!(Foo::B(_) = a);
+    }
+
+    #[test]
+    #[should_panic(expected = "o noes")]
+    fn test_assert_let_panic_with_message() {
+        let a = Foo::A(123);
+        assert_let!(Foo::B(_) = a, "o noes");
+    }
+
+    #[test]
+    fn test_assert_matches_any_succeed() {
+        let a = Foo::A(123);
+        assert_matches_any!(a, [Foo::B(_), Foo::A(n) if n == 123]);
+
+        let b = Foo::B("foo");
+        assert_matches_any!(b, [Foo::B(_)], "o noes");
+    }
+
+    #[test]
+    #[should_panic(expected = "matched none of: `Foo::B(_)`, `Foo::A(n) if n == 0`")]
+    fn test_assert_matches_any_panic() {
+        let a = Foo::A(123);
+        assert_matches_any!(a, [Foo::B(_), Foo::A(n) if n == 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "o noes")]
+    fn test_assert_matches_any_panic_with_message() {
+        let a = Foo::A(123);
+        assert_matches_any!(a, [Foo::B(_)], "o noes");
+    }
 }